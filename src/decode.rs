@@ -0,0 +1,156 @@
+use axum::{extract::Json, response::Json as JsonResponse};
+use serde_json::json;
+use spl_token::{
+    instruction::TokenInstruction,
+    solana_program::program_pack::Pack,
+    state::{Account, Mint},
+};
+use validator::Validate;
+
+use crate::models::*;
+use crate::utils::*;
+
+// Decode raw SPL Token instruction data back into its human-readable variant
+pub async fn decode_instruction(
+    Json(payload): Json<DecodeInstructionRequest>,
+) -> JsonResponse<ApiResponse<DecodeInstructionResponse>> {
+    if let Err(e) = payload.validate() {
+        return JsonResponse(ApiResponse::error(format!("Validation error: {}", e)));
+    }
+
+    let program_id = match base58_to_pubkey(&payload.program_id) {
+        Ok(pk) => pk,
+        Err(e) => return JsonResponse(ApiResponse::error(format!("Invalid program id: {}", e))),
+    };
+
+    if program_id != spl_token::id() {
+        return JsonResponse(ApiResponse::error(
+            "Only the SPL Token program is supported for decoding".to_string(),
+        ));
+    }
+
+    let data = match base64_to_bytes(&payload.instruction_data) {
+        Ok(bytes) => bytes,
+        Err(e) => return JsonResponse(ApiResponse::error(format!("Invalid instruction data: {}", e))),
+    };
+
+    let instruction = match TokenInstruction::unpack(&data) {
+        Ok(ix) => ix,
+        Err(e) => return JsonResponse(ApiResponse::error(format!("Failed to decode instruction: {}", e))),
+    };
+
+    let (name, fields) = describe_token_instruction(&instruction, &payload.accounts);
+
+    JsonResponse(ApiResponse::success(DecodeInstructionResponse {
+        program: "spl-token".to_string(),
+        instruction: name,
+        fields,
+    }))
+}
+
+// Label `accounts` by their role for the given instruction variant, in the
+// order the SPL Token program processor expects them
+fn labeled_accounts(roles: &[&str], accounts: &[String]) -> serde_json::Map<String, serde_json::Value> {
+    roles
+        .iter()
+        .zip(accounts.iter())
+        .map(|(role, pubkey)| (role.to_string(), json!(pubkey)))
+        .collect()
+}
+
+fn describe_token_instruction(
+    instruction: &TokenInstruction,
+    accounts: &[String],
+) -> (String, serde_json::Value) {
+    match instruction {
+        TokenInstruction::InitializeMint {
+            decimals,
+            mint_authority,
+            freeze_authority,
+        } => {
+            let mut fields = labeled_accounts(&["mint"], accounts);
+            fields.insert("decimals".to_string(), json!(decimals));
+            fields.insert("mint_authority".to_string(), json!(mint_authority.to_string()));
+            fields.insert(
+                "freeze_authority".to_string(),
+                json!(Option::from(*freeze_authority).map(|pk: solana_sdk::pubkey::Pubkey| pk.to_string())),
+            );
+            ("InitializeMint".to_string(), serde_json::Value::Object(fields))
+        }
+        TokenInstruction::MintTo { amount } => {
+            let mut fields = labeled_accounts(&["mint", "destination", "authority"], accounts);
+            fields.insert("amount".to_string(), json!(amount));
+            ("MintTo".to_string(), serde_json::Value::Object(fields))
+        }
+        TokenInstruction::Transfer { amount } => {
+            let mut fields = labeled_accounts(&["source", "destination", "owner"], accounts);
+            fields.insert("amount".to_string(), json!(amount));
+            ("Transfer".to_string(), serde_json::Value::Object(fields))
+        }
+        TokenInstruction::TransferChecked { amount, decimals } => {
+            let mut fields = labeled_accounts(&["source", "mint", "destination", "authority"], accounts);
+            fields.insert("amount".to_string(), json!(amount));
+            fields.insert("decimals".to_string(), json!(decimals));
+            ("TransferChecked".to_string(), serde_json::Value::Object(fields))
+        }
+        other => (
+            "Unknown".to_string(),
+            json!({ "raw": format!("{:?}", other) }),
+        ),
+    }
+}
+
+// Unpack raw SPL Token account data into its Mint or token-account fields
+pub async fn parse_account(
+    Json(payload): Json<ParseAccountRequest>,
+) -> JsonResponse<ApiResponse<ParseAccountResponse>> {
+    if let Err(e) = payload.validate() {
+        return JsonResponse(ApiResponse::error(format!("Validation error: {}", e)));
+    }
+
+    let program_id = match base58_to_pubkey(&payload.program_id) {
+        Ok(pk) => pk,
+        Err(e) => return JsonResponse(ApiResponse::error(format!("Invalid program id: {}", e))),
+    };
+
+    if program_id != spl_token::id() {
+        return JsonResponse(ApiResponse::error(
+            "Only SPL Token accounts are supported for parsing".to_string(),
+        ));
+    }
+
+    let data = match base64_to_bytes(&payload.data) {
+        Ok(bytes) => bytes,
+        Err(e) => return JsonResponse(ApiResponse::error(format!("Invalid account data: {}", e))),
+    };
+
+    if let Ok(mint) = Mint::unpack(&data) {
+        return JsonResponse(ApiResponse::success(ParseAccountResponse {
+            account_type: "Mint".to_string(),
+            fields: json!({
+                "mint_authority": Option::from(mint.mint_authority).map(|pk: solana_sdk::pubkey::Pubkey| pk.to_string()),
+                "supply": mint.supply,
+                "decimals": mint.decimals,
+                "is_initialized": mint.is_initialized,
+                "freeze_authority": Option::from(mint.freeze_authority).map(|pk: solana_sdk::pubkey::Pubkey| pk.to_string()),
+            }),
+        }));
+    }
+
+    match Account::unpack(&data) {
+        Ok(account) => JsonResponse(ApiResponse::success(ParseAccountResponse {
+            account_type: "Account".to_string(),
+            fields: json!({
+                "mint": account.mint.to_string(),
+                "owner": account.owner.to_string(),
+                "amount": account.amount,
+                "delegate": Option::from(account.delegate).map(|pk: solana_sdk::pubkey::Pubkey| pk.to_string()),
+                "state": format!("{:?}", account.state),
+                "is_native": account.is_native(),
+                "delegated_amount": account.delegated_amount,
+                "close_authority": Option::from(account.close_authority).map(|pk: solana_sdk::pubkey::Pubkey| pk.to_string()),
+            }),
+        })),
+        Err(e) => JsonResponse(ApiResponse::error(format!("Failed to parse account: {}", e))),
+    }
+}
@@ -1,28 +1,81 @@
 use axum::{
     extract::Json,
-    http::StatusCode,
     response::Json as JsonResponse,
 };
-use solana_sdk::{
-    pubkey::Pubkey,
-    signature::{Keypair, Signer},
-    system_instruction,
-};
-use spl_token::instruction as token_instruction;
+use solana_sdk::signature::{Keypair, Signer};
 use validator::Validate;
 
+use crate::hd::*;
 use crate::models::*;
 use crate::utils::*;
 
-// Generate a new Solana keypair
-pub async fn generate_keypair() -> JsonResponse<ApiResponse<KeypairResponse>> {
-    let keypair = Keypair::new();
-    let pubkey = pubkey_to_base58(&keypair.pubkey());
-    let secret = bs58::encode(keypair.to_bytes()).into_string();
+// Basic liveness check
+pub async fn ping() -> &'static str {
+    "pong"
+}
+
+// Generate a new Solana keypair: a plain random key by default, or derived
+// from a BIP39 mnemonic when one is supplied or requested
+pub async fn generate_keypair(
+    payload: Option<Json<GenerateKeypairRequest>>,
+) -> JsonResponse<ApiResponse<KeypairResponse>> {
+    let payload = payload.map(|Json(payload)| payload).unwrap_or_default();
+    let path = payload
+        .derivation_path
+        .as_deref()
+        .unwrap_or(SOLANA_DERIVATION_PATH);
+    let passphrase = payload.passphrase.as_deref().unwrap_or("");
+
+    let phrase = if let Some(phrase) = &payload.mnemonic {
+        Some(phrase.clone())
+    } else if payload.generate {
+        Some(generate_mnemonic().to_string())
+    } else {
+        None
+    };
+
+    let Some(phrase) = phrase else {
+        let keypair = Keypair::new();
+        return JsonResponse(ApiResponse {
+            success: true,
+            data: Some(KeypairResponse {
+                pubkey: pubkey_to_base58(&keypair.pubkey()),
+                secret: bs58::encode(keypair.to_bytes()).into_string(),
+                mnemonic: None,
+            }),
+            error: None,
+        });
+    };
+
+    let seed = match mnemonic_to_seed(&phrase, passphrase) {
+        Ok(seed) => seed,
+        Err(e) => {
+            return JsonResponse(ApiResponse {
+                success: false,
+                data: None,
+                error: Some(e.to_string()),
+            });
+        }
+    };
+
+    let keypair = match derive_keypair(&seed, path) {
+        Ok(kp) => kp,
+        Err(e) => {
+            return JsonResponse(ApiResponse {
+                success: false,
+                data: None,
+                error: Some(e.to_string()),
+            });
+        }
+    };
 
     JsonResponse(ApiResponse {
         success: true,
-        data: Some(KeypairResponse { pubkey, secret }),
+        data: Some(KeypairResponse {
+            pubkey: pubkey_to_base58(&keypair.pubkey()),
+            secret: bs58::encode(keypair.to_bytes()).into_string(),
+            mnemonic: payload.mnemonic.is_none().then_some(phrase),
+        }),
         error: None,
     })
 }
@@ -206,7 +259,7 @@ pub async fn sign_message(
     // Sign message
     let message_bytes = payload.message.as_bytes();
     let signature = keypair.sign_message(message_bytes);
-    let signature_base64 = bytes_to_base64(&signature.as_ref());
+    let signature_base64 = bytes_to_base64(signature.as_ref());
 
     JsonResponse(ApiResponse {
         success: true,
@@ -396,15 +449,15 @@ pub async fn send_token(
         }
     };
 
-    // For token transfer, we need source and destination token accounts
-    // Since we don't have the source account in the request, we'll create a placeholder
-    // In a real implementation, you'd need to derive the source account from owner + mint
-    let source = owner; // This is a simplification
+    // Token transfers move funds between associated token accounts, not
+    // between the wallets themselves
+    let source = derive_associated_token_account(&owner, &mint);
+    let destination_ata = derive_associated_token_account(&destination, &mint);
 
     // Create instruction
     let (program_id, instruction_data) = create_token_transfer_instruction(
         &source,
-        &destination,
+        &destination_ata,
         &owner,
         payload.amount,
     );
@@ -416,7 +469,7 @@ pub async fn send_token(
             is_writable: true,
         },
         AccountMeta {
-            pubkey: pubkey_to_base58(&destination),
+            pubkey: pubkey_to_base58(&destination_ata),
             is_signer: false,
             is_writable: true,
         },
@@ -436,4 +489,79 @@ pub async fn send_token(
         }),
         error: None,
     })
-} 
\ No newline at end of file
+} 
+// Derive the associated token account for a wallet + mint pair, optionally
+// emitting the instruction to create it
+pub async fn derive_ata(
+    Json(payload): Json<DeriveAtaRequest>,
+) -> JsonResponse<ApiResponse<DeriveAtaResponse>> {
+    // Validate request
+    if let Err(e) = payload.validate() {
+        return JsonResponse(ApiResponse::error(format!("Validation error: {}", e)));
+    }
+
+    let wallet = match base58_to_pubkey(&payload.wallet) {
+        Ok(pk) => pk,
+        Err(e) => return JsonResponse(ApiResponse::error(format!("Invalid wallet: {}", e))),
+    };
+
+    let mint = match base58_to_pubkey(&payload.mint) {
+        Ok(pk) => pk,
+        Err(e) => return JsonResponse(ApiResponse::error(format!("Invalid mint: {}", e))),
+    };
+
+    let ata = derive_associated_token_account(&wallet, &mint);
+
+    let create_instruction = if payload.create_if_missing {
+        let payer = match payload.payer.as_deref().map(base58_to_pubkey).transpose() {
+            Ok(pk) => pk.unwrap_or(wallet),
+            Err(e) => return JsonResponse(ApiResponse::error(format!("Invalid payer: {}", e))),
+        };
+
+        let (program_id, instruction_data) = create_associated_token_account_instruction();
+
+        Some(InstructionResponse {
+            program_id: pubkey_to_base58(&program_id),
+            accounts: vec![
+                AccountMeta {
+                    pubkey: pubkey_to_base58(&payer),
+                    is_signer: true,
+                    is_writable: true,
+                },
+                AccountMeta {
+                    pubkey: pubkey_to_base58(&ata),
+                    is_signer: false,
+                    is_writable: true,
+                },
+                AccountMeta {
+                    pubkey: pubkey_to_base58(&wallet),
+                    is_signer: false,
+                    is_writable: false,
+                },
+                AccountMeta {
+                    pubkey: pubkey_to_base58(&mint),
+                    is_signer: false,
+                    is_writable: false,
+                },
+                AccountMeta {
+                    pubkey: pubkey_to_base58(&solana_sdk::system_program::id()),
+                    is_signer: false,
+                    is_writable: false,
+                },
+                AccountMeta {
+                    pubkey: pubkey_to_base58(&spl_token::id()),
+                    is_signer: false,
+                    is_writable: false,
+                },
+            ],
+            instruction_data: bytes_to_base64(&instruction_data),
+        })
+    } else {
+        None
+    };
+
+    JsonResponse(ApiResponse::success(DeriveAtaResponse {
+        address: pubkey_to_base58(&ata),
+        create_instruction,
+    }))
+}
@@ -0,0 +1,75 @@
+use anyhow::{anyhow, Result};
+use bip39::Mnemonic;
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+use solana_sdk::signature::{Keypair, SeedDerivable};
+
+type HmacSha512 = Hmac<Sha512>;
+
+// The standard Solana BIP44 derivation path
+pub const SOLANA_DERIVATION_PATH: &str = "m/44'/501'/0'/0'";
+
+// Generate a fresh 12-word English mnemonic
+pub fn generate_mnemonic() -> Mnemonic {
+    Mnemonic::generate(12).expect("12 is a valid BIP39 word count")
+}
+
+// PBKDF2-HMAC-SHA512 mnemonic-to-seed, per BIP39
+pub fn mnemonic_to_seed(phrase: &str, passphrase: &str) -> Result<[u8; 64]> {
+    let mnemonic = Mnemonic::parse_normalized(phrase).map_err(|e| anyhow!("Invalid mnemonic: {}", e))?;
+    Ok(mnemonic.to_seed_normalized(passphrase))
+}
+
+// Derive an ed25519 keypair from a BIP39 seed via SLIP-0010, then expand the
+// resulting 32-byte key into a Solana keypair
+pub fn derive_keypair(seed: &[u8], path: &str) -> Result<Keypair> {
+    let secret = derive_slip10_ed25519(seed, path)?;
+    Keypair::from_seed(&secret).map_err(|e| anyhow!("Failed to derive keypair: {}", e))
+}
+
+// ed25519 only supports hardened derivation, so every path segment is
+// forced hardened regardless of whether it was written with a `'`
+fn derive_slip10_ed25519(seed: &[u8], path: &str) -> Result<[u8; 32]> {
+    let (mut key, mut chain_code) = master_key(seed);
+
+    for index in parse_path(path)? {
+        let mut data = Vec::with_capacity(1 + 32 + 4);
+        data.push(0u8);
+        data.extend_from_slice(&key);
+        data.extend_from_slice(&(index | 0x8000_0000).to_be_bytes());
+
+        let mut mac = HmacSha512::new_from_slice(&chain_code).expect("HMAC accepts any key length");
+        mac.update(&data);
+        let result = mac.finalize().into_bytes();
+
+        key.copy_from_slice(&result[..32]);
+        chain_code.copy_from_slice(&result[32..]);
+    }
+
+    Ok(key)
+}
+
+fn master_key(seed: &[u8]) -> ([u8; 32], [u8; 32]) {
+    let mut mac = HmacSha512::new_from_slice(b"ed25519 seed").expect("HMAC accepts any key length");
+    mac.update(seed);
+    let result = mac.finalize().into_bytes();
+
+    let mut key = [0u8; 32];
+    let mut chain_code = [0u8; 32];
+    key.copy_from_slice(&result[..32]);
+    chain_code.copy_from_slice(&result[32..]);
+    (key, chain_code)
+}
+
+fn parse_path(path: &str) -> Result<Vec<u32>> {
+    path.trim_start_matches("m/")
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| {
+            segment
+                .trim_end_matches('\'')
+                .parse::<u32>()
+                .map_err(|e| anyhow!("Invalid derivation path segment '{}': {}", segment, e))
+        })
+        .collect()
+}
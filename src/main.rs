@@ -1,33 +1,22 @@
 use axum::{
-    extract::Json,
-    http::StatusCode,
-    response::Json as JsonResponse,
     routing::{post, get},
     Router,
 };
-use serde::{Deserialize, Serialize};
-use solana_sdk::{
-    pubkey::Pubkey,
-    signature::{Keypair, Signer},
-    signer::SignerError,
-    system_instruction,
-    transaction::Transaction,
-};
-use spl_token::{
-    instruction as token_instruction,
-    state::{Mint, Account},
-};
-use std::str::FromStr;
 use tower_http::cors::CorsLayer;
-use tracing::{info, error};
+use tracing::info;
 
+mod decode;
 mod handlers;
+mod hd;
+mod metadata;
 mod models;
+mod rpc;
 mod utils;
 
+use decode::*;
 use handlers::*;
-use models::*;
-use utils::*;
+use metadata::*;
+use rpc::*;
 
 #[tokio::main]
 async fn main() {
@@ -49,6 +38,15 @@ async fn main() {
         .route("/message/verify", post(verify_message))
         .route("/send/sol", post(send_sol))
         .route("/send/token", post(send_token))
+        .route("/token/ata", post(derive_ata))
+        .route("/transaction/send", post(send_transaction))
+        .route("/transaction/build", post(build_transaction))
+        .route("/account/create", post(create_account))
+        .route("/rent/exempt", get(rent_exempt))
+        .route("/instruction/decode", post(decode_instruction))
+        .route("/account/parse", post(parse_account))
+        .route("/token/metadata", post(create_token_metadata))
+        .route("/nft/create", post(create_nft))
         .layer(cors);
 
     // Run it
@@ -0,0 +1,206 @@
+use axum::{extract::Json, response::Json as JsonResponse};
+use mpl_token_metadata::instructions::{CreateMasterEditionV3Builder, CreateMetadataAccountV3Builder};
+use mpl_token_metadata::types::{Creator, DataV2};
+use solana_sdk::{pubkey::Pubkey, system_program};
+use std::str::FromStr;
+use validator::Validate;
+
+use crate::models::*;
+use crate::utils::*;
+
+// Metaplex Token Metadata program id
+pub fn metadata_program_id() -> Pubkey {
+    Pubkey::from_str("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s")
+        .expect("hardcoded metadata program id is valid")
+}
+
+// Derive the metadata PDA for a mint
+pub fn derive_metadata_pda(mint: &Pubkey) -> Pubkey {
+    let (pda, _bump) = Pubkey::find_program_address(
+        &[b"metadata", metadata_program_id().as_ref(), mint.as_ref()],
+        &metadata_program_id(),
+    );
+    pda
+}
+
+// Derive the master edition PDA for a mint
+pub fn derive_master_edition_pda(mint: &Pubkey) -> Pubkey {
+    let (pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"metadata",
+            metadata_program_id().as_ref(),
+            mint.as_ref(),
+            b"edition",
+        ],
+        &metadata_program_id(),
+    );
+    pda
+}
+
+fn parse_creators(input: &Option<Vec<CreatorInput>>) -> Result<Option<Vec<Creator>>, String> {
+    let Some(creators) = input else {
+        return Ok(None);
+    };
+
+    let mut parsed = Vec::with_capacity(creators.len());
+    for creator in creators {
+        let address = base58_to_pubkey(&creator.address)
+            .map_err(|e| format!("Invalid creator address: {}", e))?;
+        parsed.push(Creator {
+            address,
+            verified: creator.verified,
+            share: creator.share,
+        });
+    }
+    Ok(Some(parsed))
+}
+
+// Build a `create_metadata_accounts_v3` instruction attaching Metaplex
+// metadata to an existing mint
+pub async fn create_token_metadata(
+    Json(payload): Json<CreateMetadataRequest>,
+) -> JsonResponse<ApiResponse<InstructionResponse>> {
+    if let Err(e) = payload.validate() {
+        return JsonResponse(ApiResponse::error(format!("Validation error: {}", e)));
+    }
+
+    let mint = match base58_to_pubkey(&payload.mint) {
+        Ok(pk) => pk,
+        Err(e) => return JsonResponse(ApiResponse::error(format!("Invalid mint: {}", e))),
+    };
+
+    let mint_authority = match base58_to_pubkey(&payload.mint_authority) {
+        Ok(pk) => pk,
+        Err(e) => return JsonResponse(ApiResponse::error(format!("Invalid mint authority: {}", e))),
+    };
+
+    let payer = match base58_to_pubkey(&payload.payer) {
+        Ok(pk) => pk,
+        Err(e) => return JsonResponse(ApiResponse::error(format!("Invalid payer: {}", e))),
+    };
+
+    let update_authority = match payload
+        .update_authority
+        .as_deref()
+        .map(base58_to_pubkey)
+        .transpose()
+    {
+        Ok(pk) => pk.unwrap_or(mint_authority),
+        Err(e) => return JsonResponse(ApiResponse::error(format!("Invalid update authority: {}", e))),
+    };
+
+    let creators = match parse_creators(&payload.creators) {
+        Ok(c) => c,
+        Err(e) => return JsonResponse(ApiResponse::error(e)),
+    };
+
+    let metadata_account = derive_metadata_pda(&mint);
+
+    let instruction = CreateMetadataAccountV3Builder::new()
+        .metadata(metadata_account)
+        .mint(mint)
+        .mint_authority(mint_authority)
+        .payer(payer)
+        .update_authority(update_authority, true)
+        .system_program(system_program::id())
+        .data(DataV2 {
+            name: payload.name,
+            symbol: payload.symbol,
+            uri: payload.uri,
+            seller_fee_basis_points: payload.seller_fee_basis_points,
+            creators,
+            collection: None,
+            uses: None,
+        })
+        .is_mutable(true)
+        .instruction();
+
+    JsonResponse(ApiResponse::success(instruction_to_response(&instruction)))
+}
+
+// Build the ordered instruction sequence for a 0-decimal NFT mint: initialize
+// mint, attach metadata, and optionally lock it down with a master edition
+pub async fn create_nft(
+    Json(payload): Json<CreateNftRequest>,
+) -> JsonResponse<ApiResponse<NftInstructionsResponse>> {
+    if let Err(e) = payload.validate() {
+        return JsonResponse(ApiResponse::error(format!("Validation error: {}", e)));
+    }
+
+    let mint = match base58_to_pubkey(&payload.mint) {
+        Ok(pk) => pk,
+        Err(e) => return JsonResponse(ApiResponse::error(format!("Invalid mint: {}", e))),
+    };
+
+    let mint_authority = match base58_to_pubkey(&payload.mint_authority) {
+        Ok(pk) => pk,
+        Err(e) => return JsonResponse(ApiResponse::error(format!("Invalid mint authority: {}", e))),
+    };
+
+    let payer = match base58_to_pubkey(&payload.payer) {
+        Ok(pk) => pk,
+        Err(e) => return JsonResponse(ApiResponse::error(format!("Invalid payer: {}", e))),
+    };
+
+    let creators = match parse_creators(&payload.creators) {
+        Ok(c) => c,
+        Err(e) => return JsonResponse(ApiResponse::error(e)),
+    };
+
+    let (mint_program_id, mint_instruction_data) =
+        create_initialize_mint_instruction(&mint, 0, &mint_authority);
+    let initialize_mint = InstructionResponse {
+        program_id: pubkey_to_base58(&mint_program_id),
+        accounts: vec![
+            AccountMeta {
+                pubkey: pubkey_to_base58(&mint),
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: pubkey_to_base58(&mint_authority),
+                is_signer: true,
+                is_writable: false,
+            },
+        ],
+        instruction_data: bytes_to_base64(&mint_instruction_data),
+    };
+
+    let metadata_account = derive_metadata_pda(&mint);
+    let create_metadata = CreateMetadataAccountV3Builder::new()
+        .metadata(metadata_account)
+        .mint(mint)
+        .mint_authority(mint_authority)
+        .payer(payer)
+        .update_authority(mint_authority, true)
+        .system_program(system_program::id())
+        .data(DataV2 {
+            name: payload.name,
+            symbol: payload.symbol,
+            uri: payload.uri,
+            seller_fee_basis_points: payload.seller_fee_basis_points,
+            creators,
+            collection: None,
+            uses: None,
+        })
+        .is_mutable(true)
+        .instruction();
+
+    let mut instructions = vec![initialize_mint, instruction_to_response(&create_metadata)];
+
+    if payload.with_master_edition {
+        let master_edition_account = derive_master_edition_pda(&mint);
+        let create_master_edition = CreateMasterEditionV3Builder::new()
+            .edition(master_edition_account)
+            .mint(mint)
+            .update_authority(mint_authority)
+            .mint_authority(mint_authority)
+            .payer(payer)
+            .metadata(metadata_account)
+            .max_supply(0)
+            .instruction();
+        instructions.push(instruction_to_response(&create_master_edition));
+    }
+
+    JsonResponse(ApiResponse::success(NftInstructionsResponse { instructions }))
+}
@@ -32,6 +32,20 @@ impl<T> ApiResponse<T> {
 pub struct KeypairResponse {
     pub pubkey: String,
     pub secret: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mnemonic: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+pub struct GenerateKeypairRequest {
+    #[serde(default)]
+    pub mnemonic: Option<String>,
+    #[serde(default)]
+    pub passphrase: Option<String>,
+    #[serde(default)]
+    pub derivation_path: Option<String>,
+    #[serde(default)]
+    pub generate: bool,
 }
 
 #[derive(Deserialize, Validate)]
@@ -97,14 +111,118 @@ pub struct SendTokenRequest {
     pub amount: u64,
 }
 
+#[derive(Deserialize, Validate)]
+pub struct DecodeInstructionRequest {
+    #[validate(length(min = 32, max = 44))]
+    pub program_id: String,
+    pub accounts: Vec<String>,
+    pub instruction_data: String,
+}
+
+#[derive(Serialize)]
+pub struct DecodeInstructionResponse {
+    pub program: String,
+    pub instruction: String,
+    pub fields: serde_json::Value,
+}
+
+#[derive(Deserialize, Validate)]
+pub struct ParseAccountRequest {
+    #[validate(length(min = 32, max = 44))]
+    pub program_id: String,
+    pub data: String,
+}
+
+#[derive(Serialize)]
+pub struct ParseAccountResponse {
+    pub account_type: String,
+    pub fields: serde_json::Value,
+}
+
+#[derive(Deserialize, Validate)]
+pub struct DeriveAtaRequest {
+    #[validate(length(min = 32, max = 44))]
+    pub wallet: String,
+    #[validate(length(min = 32, max = 44))]
+    pub mint: String,
+    #[serde(default)]
+    pub create_if_missing: bool,
+    #[serde(default)]
+    pub payer: Option<String>,
+}
+
 #[derive(Serialize)]
+pub struct DeriveAtaResponse {
+    pub address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_instruction: Option<InstructionResponse>,
+}
+
+#[derive(Deserialize, Validate)]
+pub struct CreatorInput {
+    #[validate(length(min = 32, max = 44))]
+    pub address: String,
+    #[serde(default)]
+    pub verified: bool,
+    pub share: u8,
+}
+
+#[derive(Deserialize, Validate)]
+pub struct CreateMetadataRequest {
+    #[validate(length(min = 32, max = 44))]
+    pub mint: String,
+    #[validate(length(min = 32, max = 44))]
+    pub mint_authority: String,
+    #[validate(length(min = 32, max = 44))]
+    pub payer: String,
+    #[serde(default)]
+    pub update_authority: Option<String>,
+    #[validate(length(min = 1, max = 32))]
+    pub name: String,
+    #[validate(length(min = 1, max = 10))]
+    pub symbol: String,
+    #[validate(length(min = 1, max = 200))]
+    pub uri: String,
+    pub seller_fee_basis_points: u16,
+    #[serde(default)]
+    pub creators: Option<Vec<CreatorInput>>,
+}
+
+#[derive(Deserialize, Validate)]
+pub struct CreateNftRequest {
+    #[validate(length(min = 32, max = 44))]
+    pub mint: String,
+    #[validate(length(min = 32, max = 44))]
+    pub mint_authority: String,
+    #[validate(length(min = 32, max = 44))]
+    pub payer: String,
+    #[validate(length(min = 1, max = 32))]
+    pub name: String,
+    #[validate(length(min = 1, max = 10))]
+    pub symbol: String,
+    #[validate(length(min = 1, max = 200))]
+    pub uri: String,
+    #[serde(default)]
+    pub seller_fee_basis_points: u16,
+    #[serde(default)]
+    pub creators: Option<Vec<CreatorInput>>,
+    #[serde(default)]
+    pub with_master_edition: bool,
+}
+
+#[derive(Serialize)]
+pub struct NftInstructionsResponse {
+    pub instructions: Vec<InstructionResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
 pub struct InstructionResponse {
     pub program_id: String,
     pub accounts: Vec<AccountMeta>,
     pub instruction_data: String,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct AccountMeta {
     pub pubkey: String,
     pub is_signer: bool,
@@ -125,23 +243,66 @@ pub struct VerifyMessageResponse {
     pub pubkey: String,
 }
 
+#[derive(Deserialize)]
+pub struct SendTransactionRequest {
+    pub instructions: Vec<InstructionResponse>,
+    pub signers: Vec<String>,
+    #[serde(default)]
+    pub fee_payer: Option<String>,
+    #[serde(default)]
+    pub simulate: bool,
+}
+
 #[derive(Serialize)]
-pub struct SendSolResponse {
-    pub program_id: String,
-    pub accounts: Vec<String>,
-    pub instruction_data: String,
+pub struct SendTransactionResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+    pub simulated: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logs: Option<Vec<String>>,
+}
+
+#[derive(Deserialize)]
+pub struct BuildTransactionRequest {
+    pub instructions: Vec<InstructionResponse>,
+    pub fee_payer: String,
+    #[serde(default)]
+    pub recent_blockhash: Option<String>,
 }
 
 #[derive(Serialize)]
-pub struct SendTokenResponse {
-    pub program_id: String,
-    pub accounts: Vec<SendTokenAccountMeta>,
-    pub instruction_data: String,
+pub struct BuildTransactionResponse {
+    pub transaction: String,
+    pub signers: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountType {
+    Mint,
+    TokenAccount,
+}
+
+#[derive(Deserialize, Validate)]
+pub struct CreateAccountRequest {
+    #[validate(length(min = 32, max = 44))]
+    pub payer: String,
+    #[validate(length(min = 32, max = 44))]
+    pub new_account: String,
+    #[validate(length(min = 32, max = 44))]
+    pub owner: String,
+    #[serde(default)]
+    pub space: Option<u64>,
+    #[serde(default)]
+    pub account_type: Option<AccountType>,
+}
+
+#[derive(Deserialize)]
+pub struct RentExemptQuery {
+    pub space: u64,
 }
 
 #[derive(Serialize)]
-pub struct SendTokenAccountMeta {
-    pub pubkey: String,
-    #[serde(rename = "isSigner")]
-    pub is_signer: bool,
+pub struct RentExemptResponse {
+    pub lamports: u64,
 }
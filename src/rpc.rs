@@ -0,0 +1,287 @@
+use axum::{
+    extract::{Json, Query},
+    response::Json as JsonResponse,
+};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    hash::Hash,
+    message::Message,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_token::solana_program::program_pack::Pack;
+use spl_token::state::{Account as TokenAccountState, Mint as MintState};
+use std::collections::HashSet;
+use std::env;
+use std::str::FromStr;
+use validator::Validate;
+
+use crate::models::*;
+use crate::utils::*;
+
+// Which Solana cluster RPC requests are broadcast to
+pub enum Cluster {
+    Mainnet,
+    Devnet,
+    Testnet,
+    Localnet,
+    Custom(String),
+}
+
+impl Cluster {
+    pub fn url(&self) -> String {
+        match self {
+            Cluster::Mainnet => "https://api.mainnet-beta.solana.com".to_string(),
+            Cluster::Devnet => "https://api.devnet.solana.com".to_string(),
+            Cluster::Testnet => "https://api.testnet.solana.com".to_string(),
+            Cluster::Localnet => "http://127.0.0.1:8899".to_string(),
+            Cluster::Custom(url) => url.clone(),
+        }
+    }
+
+    // Read the target cluster from `SOLANA_CLUSTER`, defaulting to devnet
+    pub fn from_env() -> Self {
+        match env::var("SOLANA_CLUSTER") {
+            Ok(value) => Self::parse(&value),
+            Err(_) => Cluster::Devnet,
+        }
+    }
+
+    fn parse(value: &str) -> Self {
+        match value.to_lowercase().as_str() {
+            "mainnet" | "mainnet-beta" => Cluster::Mainnet,
+            "devnet" => Cluster::Devnet,
+            "testnet" => Cluster::Testnet,
+            "localnet" | "localhost" => Cluster::Localnet,
+            _ => Cluster::Custom(value.to_string()),
+        }
+    }
+}
+
+fn rpc_client() -> RpcClient {
+    RpcClient::new_with_commitment(Cluster::from_env().url(), CommitmentConfig::confirmed())
+}
+
+// Build, sign, and either simulate or broadcast a transaction made up of
+// one or more already-serialized instructions
+pub async fn send_transaction(
+    Json(payload): Json<SendTransactionRequest>,
+) -> JsonResponse<ApiResponse<SendTransactionResponse>> {
+    if payload.instructions.is_empty() {
+        return JsonResponse(ApiResponse::error("At least one instruction is required".to_string()));
+    }
+
+    let mut instructions = Vec::with_capacity(payload.instructions.len());
+    for spec in &payload.instructions {
+        match instruction_from_spec(spec) {
+            Ok(ix) => instructions.push(ix),
+            Err(e) => return JsonResponse(ApiResponse::error(format!("Invalid instruction: {}", e))),
+        }
+    }
+
+    let mut signers = Vec::with_capacity(payload.signers.len());
+    for secret in &payload.signers {
+        match base58_to_keypair(secret) {
+            Ok(kp) => signers.push(kp),
+            Err(e) => return JsonResponse(ApiResponse::error(format!("Invalid signer: {}", e))),
+        }
+    }
+
+    if signers.is_empty() {
+        return JsonResponse(ApiResponse::error("At least one signer is required".to_string()));
+    }
+
+    let fee_payer = match &payload.fee_payer {
+        Some(raw) => match base58_to_pubkey(raw) {
+            Ok(pk) => pk,
+            Err(e) => return JsonResponse(ApiResponse::error(format!("Invalid fee payer: {}", e))),
+        },
+        None => signers[0].pubkey(),
+    };
+
+    let client = rpc_client();
+
+    let blockhash = match client.get_latest_blockhash().await {
+        Ok(hash) => hash,
+        Err(e) => return JsonResponse(ApiResponse::error(format!("Failed to fetch blockhash: {}", e))),
+    };
+
+    // `Transaction::sign` panics if a required signer wasn't supplied, so
+    // check coverage ourselves and return a clean error instead
+    let message = Message::new_with_blockhash(&instructions, Some(&fee_payer), &blockhash);
+    let required_signers: HashSet<Pubkey> = message
+        .account_keys
+        .iter()
+        .take(message.header.num_required_signatures as usize)
+        .cloned()
+        .collect();
+    let supplied_signers: HashSet<Pubkey> = signers.iter().map(|kp| kp.pubkey()).collect();
+
+    if let Some(missing) = required_signers.difference(&supplied_signers).next() {
+        return JsonResponse(ApiResponse::error(format!(
+            "Missing signature for required signer: {}",
+            missing
+        )));
+    }
+
+    let signer_refs: Vec<&Keypair> = signers.iter().collect();
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&fee_payer),
+        &signer_refs,
+        blockhash,
+    );
+
+    if payload.simulate {
+        return match client.simulate_transaction(&transaction).await {
+            Ok(result) => JsonResponse(ApiResponse::success(SendTransactionResponse {
+                signature: None,
+                simulated: true,
+                logs: result.value.logs,
+            })),
+            Err(e) => JsonResponse(ApiResponse::error(format!("Simulation failed: {}", e))),
+        };
+    }
+
+    match client.send_and_confirm_transaction(&transaction).await {
+        Ok(signature) => JsonResponse(ApiResponse::success(SendTransactionResponse {
+            signature: Some(signature.to_string()),
+            simulated: false,
+            logs: None,
+        })),
+        Err(e) => JsonResponse(ApiResponse::error(format!("Transaction failed: {}", e))),
+    }
+}
+
+// Assemble an ordered set of instructions into a single unsigned transaction
+// message so co-signers can sign offline before broadcasting
+pub async fn build_transaction(
+    Json(payload): Json<BuildTransactionRequest>,
+) -> JsonResponse<ApiResponse<BuildTransactionResponse>> {
+    if payload.instructions.is_empty() {
+        return JsonResponse(ApiResponse::error("At least one instruction is required".to_string()));
+    }
+
+    let mut instructions = Vec::with_capacity(payload.instructions.len());
+    for spec in &payload.instructions {
+        match instruction_from_spec(spec) {
+            Ok(ix) => instructions.push(ix),
+            Err(e) => return JsonResponse(ApiResponse::error(format!("Invalid instruction: {}", e))),
+        }
+    }
+
+    let fee_payer = match base58_to_pubkey(&payload.fee_payer) {
+        Ok(pk) => pk,
+        Err(e) => return JsonResponse(ApiResponse::error(format!("Invalid fee payer: {}", e))),
+    };
+
+    let blockhash = match &payload.recent_blockhash {
+        Some(raw) => match Hash::from_str(raw) {
+            Ok(hash) => hash,
+            Err(e) => return JsonResponse(ApiResponse::error(format!("Invalid recent blockhash: {}", e))),
+        },
+        None => match rpc_client().get_latest_blockhash().await {
+            Ok(hash) => hash,
+            Err(e) => return JsonResponse(ApiResponse::error(format!("Failed to fetch blockhash: {}", e))),
+        },
+    };
+
+    let message = Message::new_with_blockhash(&instructions, Some(&fee_payer), &blockhash);
+    let signers = message
+        .account_keys
+        .iter()
+        .take(message.header.num_required_signatures as usize)
+        .map(pubkey_to_base58)
+        .collect();
+
+    let transaction = Transaction::new_unsigned(message);
+    let serialized = match bincode::serialize(&transaction) {
+        Ok(bytes) => bytes,
+        Err(e) => return JsonResponse(ApiResponse::error(format!("Failed to serialize transaction: {}", e))),
+    };
+
+    JsonResponse(ApiResponse::success(BuildTransactionResponse {
+        transaction: bytes_to_base64(&serialized),
+        signers,
+    }))
+}
+
+fn resolve_space(payload: &CreateAccountRequest) -> Result<u64, String> {
+    if let Some(space) = payload.space {
+        return Ok(space);
+    }
+
+    match &payload.account_type {
+        Some(AccountType::Mint) => Ok(MintState::LEN as u64),
+        Some(AccountType::TokenAccount) => Ok(TokenAccountState::LEN as u64),
+        None => Err("Either `space` or `account_type` is required".to_string()),
+    }
+}
+
+// Build a `create_account` instruction sized and funded for rent exemption
+pub async fn create_account(
+    Json(payload): Json<CreateAccountRequest>,
+) -> JsonResponse<ApiResponse<InstructionResponse>> {
+    if let Err(e) = payload.validate() {
+        return JsonResponse(ApiResponse::error(format!("Validation error: {}", e)));
+    }
+
+    let payer = match base58_to_pubkey(&payload.payer) {
+        Ok(pk) => pk,
+        Err(e) => return JsonResponse(ApiResponse::error(format!("Invalid payer: {}", e))),
+    };
+
+    let new_account = match base58_to_pubkey(&payload.new_account) {
+        Ok(pk) => pk,
+        Err(e) => return JsonResponse(ApiResponse::error(format!("Invalid new account: {}", e))),
+    };
+
+    let owner = match base58_to_pubkey(&payload.owner) {
+        Ok(pk) => pk,
+        Err(e) => return JsonResponse(ApiResponse::error(format!("Invalid owner: {}", e))),
+    };
+
+    let space = match resolve_space(&payload) {
+        Ok(space) => space,
+        Err(e) => return JsonResponse(ApiResponse::error(e)),
+    };
+
+    let lamports = match rpc_client().get_minimum_balance_for_rent_exemption(space as usize).await {
+        Ok(lamports) => lamports,
+        Err(e) => return JsonResponse(ApiResponse::error(format!("Failed to fetch rent exemption: {}", e))),
+    };
+
+    let (program_id, instruction_data) =
+        create_account_instruction(&payer, &new_account, lamports, space, &owner);
+
+    let accounts = vec![
+        AccountMeta {
+            pubkey: pubkey_to_base58(&payer),
+            is_signer: true,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: pubkey_to_base58(&new_account),
+            is_signer: true,
+            is_writable: true,
+        },
+    ];
+
+    JsonResponse(ApiResponse::success(InstructionResponse {
+        program_id: pubkey_to_base58(&program_id),
+        accounts,
+        instruction_data: bytes_to_base64(&instruction_data),
+    }))
+}
+
+// Bare rent-exemption lookup so clients can size funding transfers
+pub async fn rent_exempt(
+    Query(params): Query<RentExemptQuery>,
+) -> JsonResponse<ApiResponse<RentExemptResponse>> {
+    match rpc_client().get_minimum_balance_for_rent_exemption(params.space as usize).await {
+        Ok(lamports) => JsonResponse(ApiResponse::success(RentExemptResponse { lamports })),
+        Err(e) => JsonResponse(ApiResponse::error(format!("Failed to fetch rent exemption: {}", e))),
+    }
+}
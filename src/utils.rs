@@ -1,14 +1,39 @@
 use anyhow::{anyhow, Result};
 use base64::{Engine as _, engine::general_purpose};
-use bs58;
 use solana_sdk::{
+    instruction::{AccountMeta as SolanaAccountMeta, Instruction},
     pubkey::Pubkey,
-    signature::{Keypair, Signer},
+    signature::Keypair,
     system_instruction,
 };
 use spl_token::instruction as token_instruction;
 use std::str::FromStr;
 
+use crate::models::InstructionResponse;
+
+// Associated Token Account program id (ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL)
+pub fn associated_token_program_id() -> Pubkey {
+    Pubkey::from_str("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")
+        .expect("hardcoded associated token program id is valid")
+}
+
+// Derive the associated token account for a wallet + mint pair
+pub fn derive_associated_token_account(wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+    let (ata, _bump) = Pubkey::find_program_address(
+        &[wallet.as_ref(), spl_token::id().as_ref(), mint.as_ref()],
+        &associated_token_program_id(),
+    );
+    ata
+}
+
+// The `create_associated_token_account` instruction takes no instruction data;
+// the instruction type is inferred entirely from the account list. The ATA
+// program processor reads the System Program and SPL Token program by index,
+// so both must be present as trailing readonly accounts.
+pub fn create_associated_token_account_instruction() -> (Pubkey, Vec<u8>) {
+    (associated_token_program_id(), vec![])
+}
+
 // Convert base58 string to Pubkey
 pub fn base58_to_pubkey(base58_str: &str) -> Result<Pubkey> {
     Pubkey::from_str(base58_str).map_err(|e| anyhow!("Invalid public key: {}", e))
@@ -44,16 +69,6 @@ pub fn base64_to_bytes(base64_str: &str) -> Result<Vec<u8>> {
         .map_err(|e| anyhow!("Invalid base64: {}", e))
 }
 
-// Validate Solana address format
-pub fn is_valid_solana_address(address: &str) -> bool {
-    if address.len() < 32 || address.len() > 44 {
-        return false;
-    }
-    
-    // Check if it's valid base58
-    bs58::decode(address).into_vec().is_ok()
-}
-
 // Create system program transfer instruction
 pub fn create_transfer_instruction(
     from: &Pubkey,
@@ -78,7 +93,8 @@ pub fn create_mint_instruction(
         authority,
         &[],
         amount,
-    );
+    )
+    .expect("mint_to with no multisig signers cannot fail");
     (instruction.program_id, instruction.data)
 }
 
@@ -96,7 +112,21 @@ pub fn create_token_transfer_instruction(
         owner,
         &[],
         amount,
-    );
+    )
+    .expect("transfer with no multisig signers cannot fail");
+    (instruction.program_id, instruction.data)
+}
+
+// Create a system-program `create_account` instruction funding a new,
+// rent-exempt account owned by `owner`
+pub fn create_account_instruction(
+    payer: &Pubkey,
+    new_account: &Pubkey,
+    lamports: u64,
+    space: u64,
+    owner: &Pubkey,
+) -> (Pubkey, Vec<u8>) {
+    let instruction = system_instruction::create_account(payer, new_account, lamports, space, owner);
     (instruction.program_id, instruction.data)
 }
 
@@ -112,6 +142,48 @@ pub fn create_initialize_mint_instruction(
         mint_authority,
         Some(mint_authority),
         decimals,
-    );
+    )
+    .expect("initialize_mint with valid pubkeys cannot fail");
     (instruction.program_id, instruction.data)
+}
+
+// Reconstruct a solana_sdk Instruction from a serialized InstructionResponse,
+// as returned by any of the instruction-builder endpoints
+pub fn instruction_from_spec(spec: &InstructionResponse) -> Result<Instruction> {
+    let program_id = base58_to_pubkey(&spec.program_id)?;
+
+    let accounts = spec
+        .accounts
+        .iter()
+        .map(|meta| -> Result<SolanaAccountMeta> {
+            let pubkey = base58_to_pubkey(&meta.pubkey)?;
+            Ok(if meta.is_writable {
+                SolanaAccountMeta::new(pubkey, meta.is_signer)
+            } else {
+                SolanaAccountMeta::new_readonly(pubkey, meta.is_signer)
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let data = base64_to_bytes(&spec.instruction_data)?;
+
+    Ok(Instruction { program_id, accounts, data })
+}
+
+// The inverse of `instruction_from_spec`: serialize a solana_sdk Instruction
+// built by a third-party instruction-builder function into our wire shape
+pub fn instruction_to_response(instruction: &Instruction) -> InstructionResponse {
+    InstructionResponse {
+        program_id: pubkey_to_base58(&instruction.program_id),
+        accounts: instruction
+            .accounts
+            .iter()
+            .map(|meta| crate::models::AccountMeta {
+                pubkey: pubkey_to_base58(&meta.pubkey),
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect(),
+        instruction_data: bytes_to_base64(&instruction.data),
+    }
 } 
\ No newline at end of file